@@ -0,0 +1,54 @@
+// src/backup.rs
+
+use std::error::Error;
+use std::thread;
+use std::time::Duration;
+
+use rusqlite::backup::{Backup, StepResult};
+use rusqlite::Connection;
+
+use crate::paths;
+use crate::storage;
+
+/// Copies a project's live database to `dest`, page-by-page, using
+/// SQLite's online backup API. Safe to run while the source database is
+/// open elsewhere, since the backup only holds a lock on the source for
+/// the duration of a single step.
+pub fn backup_project(project_name: &str, dest: &str) -> Result<(), Box<dyn Error>> {
+    let backend = storage::read_backend(project_name);
+    if backend != "sqlite" {
+        return Err(format!(
+            "project '{}' uses the '{}' backend; backup only applies to the sqlite backend",
+            project_name, backend
+        )
+        .into());
+    }
+
+    let src_db = paths::db_path(project_name);
+    let src = Connection::open(&src_db)?;
+    let mut dst = Connection::open(dest)?;
+
+    let backup = Backup::new(&src, &mut dst)?;
+
+    loop {
+        match backup.step(5)? {
+            StepResult::Done => break,
+            StepResult::More => {
+                let progress = backup.progress();
+                println!(
+                    "Backing up '{}': {} of {} pages remaining",
+                    project_name, progress.remaining, progress.pagecount
+                );
+            }
+            StepResult::Busy | StepResult::Locked => {
+                thread::sleep(Duration::from_millis(250));
+            }
+            other => {
+                return Err(format!("unexpected backup step result: {:?}", other).into());
+            }
+        }
+    }
+
+    println!("Backed up project '{}' to {}", project_name, dest);
+    Ok(())
+}
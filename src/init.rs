@@ -4,17 +4,26 @@ use std::path::Path;
 
 use rusqlite::{Connection, Result};
 
-pub fn init(project_name: &str) -> Result<(), Box<dyn std::error::Error>> {
+use crate::migrations;
+use crate::paths;
+use crate::storage;
+
+pub fn init(project_name: &str, backend: &str) -> Result<(), Box<dyn std::error::Error>> {
     create_project_folder(project_name)?;
     create_plan_file(project_name)?;
-    create_database(project_name)?;
-    create_plannetrc_file(project_name)?;
+
+    match backend {
+        "file" => create_tasks_dir(project_name)?,
+        _ => create_database(project_name)?,
+    }
+
+    create_plannetrc_file(project_name, backend)?;
     Ok(())
 }
 
 fn create_project_folder(project_name: &str) -> std::io::Result<()> {
     let project_folder = Path::new(project_name);
-    fs::create_dir(&project_folder)?;
+    fs::create_dir(project_folder)?;
     println!("Created project folder: {:?}", project_folder);
     Ok(())
 }
@@ -28,27 +37,48 @@ fn create_plan_file(project_name: &str) -> std::io::Result<()> {
 }
 
 fn create_database(project_name: &str) -> Result<()> {
-    let db_name = format!("{}/{}.sqlite", project_name, project_name);
+    let db_name = paths::db_path(project_name);
     let conn = Connection::open(&db_name)?;
 
-    conn.execute(
-        "CREATE TABLE IF NOT EXISTS tasks (
-            id INTEGER PRIMARY KEY,
-            name TEXT NOT NULL,
-            status TEXT NOT NULL
-            description BLOB
-        )",
-        [],
-    )?;
+    migrations::apply_migrations(&conn)?;
 
     println!("Created SQLite database: {}", db_name);
     Ok(())
 }
 
-fn create_plannetrc_file(project_name: &str) -> std::io::Result<()> {
+/// Runs any pending migrations against an already-initialized project,
+/// for upgrading databases created by an older version of plannet.
+pub fn migrate(project_name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let backend = storage::read_backend(project_name);
+    if backend != "sqlite" {
+        return Err(format!(
+            "project '{}' uses the '{}' backend; migrate only applies to the sqlite backend",
+            project_name, backend
+        )
+        .into());
+    }
+
+    let db_name = paths::db_path(project_name);
+    let conn = Connection::open(&db_name)?;
+
+    migrations::apply_migrations(&conn)?;
+
+    println!("Migrated project '{}' to the latest schema", project_name);
+    Ok(())
+}
+
+fn create_tasks_dir(project_name: &str) -> std::io::Result<()> {
+    let tasks_dir = format!("{}/tasks", project_name);
+    fs::create_dir(&tasks_dir)?;
+    println!("Created tasks folder: {}", tasks_dir);
+    Ok(())
+}
+
+fn create_plannetrc_file(project_name: &str, backend: &str) -> std::io::Result<()> {
     let file_name = format!("{}/.plannetrc", project_name);
     let mut file = File::create(&file_name)?;
     writeln!(file, "project_name = \"{}\"", project_name)?;
+    writeln!(file, "backend = \"{}\"", backend)?;
     println!("Created .plannetrc file: {}", file_name);
     Ok(())
 }
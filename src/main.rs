@@ -1,31 +1,18 @@
 // src/main.rs
 
-use clap::Parser;
 use std::env;
 use std::process;
 
+mod backup;
 mod init;
+mod migrations;
+mod paths;
+mod pool;
+mod scratch;
+mod storage;
+mod tasks;
 
 fn main() {
-    let matches = command!() // requires `cargo` feature
-        .arg(arg!([name] "Optional name to operate on"))
-        .arg(
-            arg!(
-                -c --config <FILE> "Sets a custom config file"
-            )
-            // We don't have syntax yet for optional options, so manually calling `required`
-            .required(false)
-            .value_parser(value_parser!(PathBuf)),
-        )
-        .arg(arg!(
-            -d --debug ... "Turn debugging information on"
-        ))
-        .subcommand(
-            Command::new("test")
-                .about("does testing things")
-                .arg(arg!(-l --list "lists test values").action(ArgAction::SetTrue)),
-        )
-        .get_matches();
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
         eprintln!("Usage: plannet <command> [options]");
@@ -37,11 +24,17 @@ fn main() {
     match command.as_str() {
         "init" => {
             if args.len() < 3 {
-                eprintln!("Usage: plannet init <project_name>");
+                eprintln!("Usage: plannet init <project_name> [--backend sqlite|file]");
                 process::exit(1);
             }
             let project_name = &args[2];
-            if let Err(e) = init::init(project_name) {
+            let backend = args[3..]
+                .iter()
+                .position(|a| a == "--backend")
+                .and_then(|i| args.get(3 + i + 1))
+                .map(|s| s.as_str())
+                .unwrap_or("sqlite");
+            if let Err(e) = init::init(project_name, backend) {
                 eprintln!("Error initializing project: {}", e);
                 process::exit(1);
             }
@@ -51,6 +44,149 @@ fn main() {
                 eprintln!("Usage: plannet add <project_name> <task_name>");
                 process::exit(1);
             }
+            let project_name = &args[2];
+            let task_name = &args[3];
+            if let Err(e) = storage::task_repo(project_name).and_then(|repo| repo.add(task_name)) {
+                eprintln!("Error adding task: {}", e);
+                process::exit(1);
+            }
+        }
+        "update" => {
+            if args.len() < 5 {
+                eprintln!("Usage: plannet update <project_name> <task_id> <new_name>");
+                process::exit(1);
+            }
+            let project_name = &args[2];
+            let task_id = &args[3];
+            let new_name = &args[4];
+            if let Err(e) =
+                storage::task_repo(project_name).and_then(|repo| repo.update(task_id, new_name))
+            {
+                eprintln!("Error updating task: {}", e);
+                process::exit(1);
+            }
+        }
+        "move" => {
+            if args.len() < 4 {
+                eprintln!("Usage: plannet move <project_name> <task_id>");
+                process::exit(1);
+            }
+            let project_name = &args[2];
+            let task_id = &args[3];
+            if let Err(e) =
+                storage::task_repo(project_name).and_then(|repo| repo.move_forward(task_id))
+            {
+                eprintln!("Error moving task status: {}", e);
+                process::exit(1);
+            }
+        }
+        "backup" => {
+            if args.len() < 4 {
+                eprintln!("Usage: plannet backup <project_name> <dest>");
+                process::exit(1);
+            }
+            let project_name = &args[2];
+            let dest = &args[3];
+            if let Err(e) = backup::backup_project(project_name, dest) {
+                eprintln!("Error backing up project: {}", e);
+                process::exit(1);
+            }
+        }
+        "migrate" => {
+            if args.len() < 3 {
+                eprintln!("Usage: plannet migrate <project_name>");
+                process::exit(1);
+            }
+            let project_name = &args[2];
+            if let Err(e) = init::migrate(project_name) {
+                eprintln!("Error migrating project: {}", e);
+                process::exit(1);
+            }
+        }
+        "list" => {
+            if args.len() < 3 {
+                eprintln!("Usage: plannet list <project_name> [--finished]");
+                process::exit(1);
+            }
+            let project_name = &args[2];
+            let only_finished = args[3..].iter().any(|a| a == "--finished");
+            if let Err(e) =
+                storage::task_repo(project_name).and_then(|repo| repo.list(only_finished))
+            {
+                eprintln!("Error listing tasks: {}", e);
+                process::exit(1);
+            }
+        }
+        "--memory" => {
+            if args.len() < 3 {
+                eprintln!("Usage: plannet --memory <add <task_name>|list [--finished]>");
+                process::exit(1);
+            }
+            let conn = match scratch::open_memory() {
+                Ok(conn) => conn,
+                Err(e) => {
+                    eprintln!("Error opening scratch database: {}", e);
+                    process::exit(1);
+                }
+            };
+
+            let result = match args[2].as_str() {
+                "add" => {
+                    if args.len() < 4 {
+                        eprintln!("Usage: plannet --memory add <task_name>");
+                        process::exit(1);
+                    }
+                    scratch::add_task(&conn, &args[3])
+                }
+                "list" => {
+                    let only_finished = args[3..].iter().any(|a| a == "--finished");
+                    scratch::list_tasks(&conn, only_finished)
+                }
+                other => {
+                    eprintln!("Unknown --memory action: {}", other);
+                    process::exit(1);
+                }
+            };
+            if let Err(e) = result {
+                eprintln!("Error in scratch database: {}", e);
+                process::exit(1);
+            }
+        }
+        "export" => {
+            if args.len() < 3 {
+                eprintln!("Usage: plannet export <dest.sqlite>");
+                process::exit(1);
+            }
+            let dest = &args[2];
+            let conn = match scratch::open_memory() {
+                Ok(conn) => conn,
+                Err(e) => {
+                    eprintln!("Error opening scratch database: {}", e);
+                    process::exit(1);
+                }
+            };
+            if let Err(e) = scratch::export_to(&conn, dest) {
+                eprintln!("Error exporting scratch database: {}", e);
+                process::exit(1);
+            }
+        }
+        "import" => {
+            if args.len() < 3 {
+                eprintln!("Usage: plannet import <src.sqlite>");
+                process::exit(1);
+            }
+            let src = &args[2];
+            let mut conn = match scratch::open_memory() {
+                Ok(conn) => conn,
+                Err(e) => {
+                    eprintln!("Error opening scratch database: {}", e);
+                    process::exit(1);
+                }
+            };
+            if let Err(e) = scratch::import_from(&mut conn, src) {
+                eprintln!("Error importing into scratch database: {}", e);
+                process::exit(1);
+            }
         }
         _ => {
             eprintln!("Unknown command: {}", command);
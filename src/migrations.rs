@@ -0,0 +1,92 @@
+// src/migrations.rs
+
+use rusqlite::{Connection, Result};
+
+/// Ordered, append-only list of schema migrations. Each entry is the
+/// version it upgrades the database *to*, paired with the SQL that
+/// performs the upgrade. Entries must never be reordered or removed;
+/// new schema changes are appended with the next version number.
+const MIGRATIONS: &[(u32, &str)] = &[
+    (
+        1,
+        "CREATE TABLE IF NOT EXISTS tasks (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            status TEXT NOT NULL,
+            description BLOB
+        )",
+    ),
+    (
+        2,
+        "CREATE TABLE tasks_new (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            status TEXT NOT NULL,
+            description BLOB
+        );
+        INSERT INTO tasks_new (id, name, status, description)
+            SELECT lower(
+                hex(randomblob(4)) || '-' || hex(randomblob(2)) || '-4' ||
+                substr(hex(randomblob(2)), 2) || '-' ||
+                substr('89ab', abs(random()) % 4 + 1, 1) || substr(hex(randomblob(2)), 2) || '-' ||
+                hex(randomblob(6))
+            ), name, status, description FROM tasks;
+        DROP TABLE tasks;
+        ALTER TABLE tasks_new RENAME TO tasks;",
+    ),
+];
+
+/// Applies every migration whose version is greater than the database's
+/// current `user_version`, in order, bumping `user_version` after each
+/// one succeeds so a partially-upgraded database can be resumed safely.
+pub fn apply_migrations(conn: &Connection) -> Result<()> {
+    let current_version: u32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+    for (version, sql) in MIGRATIONS {
+        if *version <= current_version {
+            continue;
+        }
+
+        let tx = conn.unchecked_transaction()?;
+        tx.execute_batch(sql)?;
+        tx.execute_batch(&format!("PRAGMA user_version = {}", version))?;
+        tx.commit()?;
+
+        println!("Applied migration {}", version);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_v1_ids_to_uuid_shaped_strings() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(MIGRATIONS[0].1).unwrap();
+        conn.execute_batch("PRAGMA user_version = 1").unwrap();
+        conn.execute(
+            "INSERT INTO tasks (name, status) VALUES (?1, ?2)",
+            rusqlite::params!["write tests", "pending"],
+        )
+        .unwrap();
+
+        apply_migrations(&conn).unwrap();
+
+        let id: String = conn
+            .query_row(
+                "SELECT id FROM tasks WHERE name = 'write tests'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+
+        assert!(
+            uuid::Uuid::parse_str(&id).is_ok(),
+            "expected a UUID-shaped id, got {}",
+            id
+        );
+    }
+}
@@ -0,0 +1,8 @@
+// src/paths.rs
+
+/// The canonical location of a project's SQLite database, shared by
+/// every module that needs to open it so the on-disk layout can't drift
+/// between callers.
+pub fn db_path(project_name: &str) -> String {
+    format!("{}/{}.sqlite", project_name, project_name)
+}
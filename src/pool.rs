@@ -0,0 +1,54 @@
+// src/pool.rs
+
+use std::time::Duration;
+
+use r2d2::{ManageConnection, Pool};
+use rusqlite::Connection;
+
+use crate::paths;
+
+/// Opens pooled connections to a single project database, configured so
+/// concurrent plannet invocations (or a future daemon) don't trip over
+/// `SQLITE_BUSY`: a generous `busy_timeout` and WAL journaling let readers
+/// and writers proceed without taking turns on a single lock.
+pub struct SqliteConnectionManager {
+    db_path: String,
+}
+
+impl SqliteConnectionManager {
+    pub fn new(db_path: &str) -> Self {
+        SqliteConnectionManager {
+            db_path: db_path.to_string(),
+        }
+    }
+}
+
+impl ManageConnection for SqliteConnectionManager {
+    type Connection = Connection;
+    type Error = rusqlite::Error;
+
+    fn connect(&self) -> Result<Connection, Self::Error> {
+        let conn = Connection::open(&self.db_path)?;
+        conn.busy_timeout(Duration::from_secs(5))?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        Ok(conn)
+    }
+
+    fn is_valid(&self, conn: &mut Connection) -> Result<(), Self::Error> {
+        conn.execute_batch("SELECT 1")
+    }
+
+    fn has_broken(&self, _conn: &mut Connection) -> bool {
+        false
+    }
+}
+
+pub type SqlitePool = Pool<SqliteConnectionManager>;
+
+/// Builds a connection pool for a project's database. Intended to be
+/// constructed once per command invocation and shared by every task
+/// operation that command performs.
+pub fn build_pool(project_name: &str) -> Result<SqlitePool, r2d2::Error> {
+    let db_name = paths::db_path(project_name);
+    Pool::new(SqliteConnectionManager::new(&db_name))
+}
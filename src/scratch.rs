@@ -0,0 +1,142 @@
+// src/scratch.rs
+
+use std::env;
+use std::error::Error;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use rusqlite::backup::{Backup, StepResult};
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+use crate::migrations;
+
+/// A well-known file outside any project folder that backs every
+/// `--memory` command for the current user. A true
+/// `Connection::open_in_memory()` dies with the process that opened it,
+/// which makes it useless for plannet: each subcommand is its own
+/// process, so `add` and a later `export` would each see an unrelated,
+/// empty database. Using one fixed per-user path instead gives the
+/// scratch session real continuity across invocations while still
+/// staying separate from any `plannet init`-managed project.
+///
+/// `--memory` is therefore NOT process-isolated: it is shared, durable
+/// state for everything this user runs with `--memory`, not a private,
+/// ephemeral sandbox. It does reset when the OS clears its temp
+/// directory.
+fn scratch_path() -> PathBuf {
+    let user = env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .unwrap_or_else(|_| "default".to_string());
+    env::temp_dir().join(format!("plannet-scratch-{}.sqlite", user))
+}
+
+/// Restricts the scratch database to owner-only access so other local
+/// users can't read another user's scratch task names.
+#[cfg(unix)]
+fn restrict_permissions(path: &std::path::Path) -> std::io::Result<()> {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut perms = fs::metadata(path)?.permissions();
+    perms.set_mode(0o600);
+    fs::set_permissions(path, perms)
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &std::path::Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Opens the scratch database for `--memory` mode, creating it and
+/// bringing it up to the latest schema on first use.
+pub fn open_memory() -> Result<Connection, Box<dyn Error>> {
+    let path = scratch_path();
+    let conn = Connection::open(&path)?;
+    restrict_permissions(&path)?;
+    migrations::apply_migrations(&conn)?;
+    Ok(conn)
+}
+
+/// Adds a task to a scratch (`--memory`) session's database.
+pub fn add_task(conn: &Connection, task_name: &str) -> rusqlite::Result<()> {
+    let task_id = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO tasks (id, name, status) VALUES (?1, ?2, ?3)",
+        params![task_id, task_name, "pending"],
+    )?;
+
+    println!(
+        "Task '{}' ({}) added to scratch database (run `plannet export <dest>` to save it to a project)",
+        task_name, task_id
+    );
+    Ok(())
+}
+
+/// Lists tasks from a scratch (`--memory`) session's database.
+pub fn list_tasks(conn: &Connection, only_finished: bool) -> rusqlite::Result<()> {
+    let query = if only_finished {
+        "SELECT id, name, status FROM tasks WHERE status = 'completed'"
+    } else {
+        "SELECT id, name, status FROM tasks WHERE status != 'completed'"
+    };
+
+    let mut stmt = conn.prepare(query)?;
+    let mut rows = stmt.query([])?;
+
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let name: String = row.get(1)?;
+        let status: String = row.get(2)?;
+        println!("[{}] {} - {}", id, name, status);
+    }
+
+    Ok(())
+}
+
+/// Copies the full contents of the scratch database out to a file on
+/// disk, using the same online backup API as `plannet backup`.
+pub fn export_to(conn: &Connection, dest: &str) -> Result<(), Box<dyn Error>> {
+    let mut dst = Connection::open(dest)?;
+    let backup = Backup::new(conn, &mut dst)?;
+
+    loop {
+        match backup.step(5)? {
+            StepResult::Done => break,
+            StepResult::More => {}
+            StepResult::Busy | StepResult::Locked => {
+                thread::sleep(Duration::from_millis(250));
+            }
+            other => {
+                return Err(format!("unexpected backup step result: {:?}", other).into());
+            }
+        }
+    }
+
+    println!("Exported scratch database to {}", dest);
+    Ok(())
+}
+
+/// Loads the full contents of a file database into the scratch database,
+/// replacing whatever it currently holds.
+pub fn import_from(conn: &mut Connection, src: &str) -> Result<(), Box<dyn Error>> {
+    let source = Connection::open(src)?;
+    let backup = Backup::new(&source, conn)?;
+
+    loop {
+        match backup.step(5)? {
+            StepResult::Done => break,
+            StepResult::More => {}
+            StepResult::Busy | StepResult::Locked => {
+                thread::sleep(Duration::from_millis(250));
+            }
+            other => {
+                return Err(format!("unexpected backup step result: {:?}", other).into());
+            }
+        }
+    }
+
+    println!("Imported {} into scratch database", src);
+    Ok(())
+}
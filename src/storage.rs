@@ -0,0 +1,195 @@
+// src/storage.rs
+
+use std::error::Error;
+use std::fs;
+
+use uuid::Uuid;
+
+use crate::pool::{self, SqlitePool};
+use crate::tasks;
+
+/// Storage-agnostic task operations. Command handlers in `main.rs` talk
+/// only to this trait, so they don't need to know whether a project's
+/// tasks live in SQLite or on the filesystem.
+pub trait TaskRepo {
+    fn add(&self, task_name: &str) -> Result<(), Box<dyn Error>>;
+    fn update(&self, task_id: &str, new_name: &str) -> Result<(), Box<dyn Error>>;
+    fn move_forward(&self, task_id: &str) -> Result<(), Box<dyn Error>>;
+    fn list(&self, only_finished: bool) -> Result<(), Box<dyn Error>>;
+}
+
+/// Backs tasks with the project's `.sqlite` database, as created by
+/// `init`. Connections are handed out by a pool built once per command,
+/// so concurrent plannet invocations don't fight over the same lock.
+pub struct SqliteTaskRepo {
+    pub project_name: String,
+    pool: SqlitePool,
+}
+
+impl SqliteTaskRepo {
+    pub fn new(project_name: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(SqliteTaskRepo {
+            project_name: project_name.to_string(),
+            pool: pool::build_pool(project_name)?,
+        })
+    }
+}
+
+impl TaskRepo for SqliteTaskRepo {
+    fn add(&self, task_name: &str) -> Result<(), Box<dyn Error>> {
+        let conn = self.pool.get()?;
+        tasks::add_task(&conn, &self.project_name, task_name)?;
+        Ok(())
+    }
+
+    fn update(&self, task_id: &str, new_name: &str) -> Result<(), Box<dyn Error>> {
+        let conn = self.pool.get()?;
+        tasks::update_task(&conn, task_id, new_name)?;
+        Ok(())
+    }
+
+    fn move_forward(&self, task_id: &str) -> Result<(), Box<dyn Error>> {
+        let conn = self.pool.get()?;
+        tasks::move_status_forward(&conn, task_id)?;
+        Ok(())
+    }
+
+    fn list(&self, only_finished: bool) -> Result<(), Box<dyn Error>> {
+        let conn = self.pool.get()?;
+        tasks::list_tasks(&conn, &self.project_name, only_finished)?;
+        Ok(())
+    }
+}
+
+/// Backs tasks with one flat file per task under `<project>/tasks/`, for
+/// environments where bundling SQLite is undesirable. Each file holds
+/// the task name on its first line and its status on its second.
+pub struct FileTaskRepo {
+    pub project_name: String,
+}
+
+impl FileTaskRepo {
+    fn tasks_dir(&self) -> String {
+        format!("{}/tasks", self.project_name)
+    }
+
+    fn task_path(&self, task_id: &str) -> String {
+        format!("{}/{}.task", self.tasks_dir(), task_id)
+    }
+
+    fn read_task(&self, task_id: &str) -> Result<(String, String), Box<dyn Error>> {
+        let contents = fs::read_to_string(self.task_path(task_id))?;
+        let mut lines = contents.lines();
+        let name = lines.next().unwrap_or_default().to_string();
+        let status = lines.next().unwrap_or("pending").to_string();
+        Ok((name, status))
+    }
+
+    fn write_task(&self, task_id: &str, name: &str, status: &str) -> Result<(), Box<dyn Error>> {
+        fs::write(self.task_path(task_id), format!("{}\n{}\n", name, status))?;
+        Ok(())
+    }
+}
+
+impl TaskRepo for FileTaskRepo {
+    fn add(&self, task_name: &str) -> Result<(), Box<dyn Error>> {
+        let task_id = Uuid::new_v4().to_string();
+        self.write_task(&task_id, task_name, "pending")?;
+        println!(
+            "Task '{}' ({}) added to project '{}'",
+            task_name, task_id, self.project_name
+        );
+        Ok(())
+    }
+
+    fn update(&self, task_id: &str, new_name: &str) -> Result<(), Box<dyn Error>> {
+        let (_, status) = self.read_task(task_id)?;
+        self.write_task(task_id, new_name, &status)?;
+        println!("Task with ID {} updated to '{}'", task_id, new_name);
+        Ok(())
+    }
+
+    fn move_forward(&self, task_id: &str) -> Result<(), Box<dyn Error>> {
+        let (name, status) = self.read_task(task_id)?;
+
+        let next_status = match status.as_str() {
+            "pending" => "in_progress",
+            "in_progress" => "completed",
+            "completed" => "completed",
+            _ => {
+                eprintln!("Invalid task status: {}", status);
+                return Ok(());
+            }
+        };
+
+        self.write_task(task_id, &name, next_status)?;
+        println!("Task with ID {} status moved to '{}'", task_id, next_status);
+        Ok(())
+    }
+
+    fn list(&self, only_finished: bool) -> Result<(), Box<dyn Error>> {
+        let mut found = false;
+
+        for entry in fs::read_dir(self.tasks_dir())? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if path.extension().and_then(|e| e.to_str()) != Some("task") {
+                continue;
+            }
+
+            let task_id = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default()
+                .to_string();
+            let (name, status) = self.read_task(&task_id)?;
+
+            if only_finished && status != "completed" {
+                continue;
+            }
+            if !only_finished && status == "completed" {
+                continue;
+            }
+
+            found = true;
+            println!("[{}] {} - {}", task_id, name, status);
+        }
+
+        if !found {
+            if only_finished {
+                println!("No finished tasks in project '{}'", self.project_name);
+            } else {
+                println!("No active tasks in project '{}'", self.project_name);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the `backend` setting recorded in a project's `.plannetrc` by
+/// `init`, defaulting to `"sqlite"` for files written before this setting
+/// existed.
+pub fn read_backend(project_name: &str) -> String {
+    let path = format!("{}/.plannetrc", project_name);
+    let contents = fs::read_to_string(path).unwrap_or_default();
+
+    for line in contents.lines() {
+        if let Some(value) = line.strip_prefix("backend = ") {
+            return value.trim_matches('"').to_string();
+        }
+    }
+
+    "sqlite".to_string()
+}
+
+/// Builds the `TaskRepo` configured for a project, based on its recorded backend.
+pub fn task_repo(project_name: &str) -> Result<Box<dyn TaskRepo>, Box<dyn Error>> {
+    match read_backend(project_name).as_str() {
+        "file" => Ok(Box::new(FileTaskRepo {
+            project_name: project_name.to_string(),
+        })),
+        _ => Ok(Box::new(SqliteTaskRepo::new(project_name)?)),
+    }
+}
@@ -1,25 +1,24 @@
 // src/tasks.rs
 
 use rusqlite::{params, Connection, Result};
+use uuid::Uuid;
 
-pub fn add_task(project_name: &str, task_name: &str) -> Result<()> {
-    let db_name = format!("{}.sqlite", project_name);
-    let conn = Connection::open(&db_name)?;
-
+pub fn add_task(conn: &Connection, project_name: &str, task_name: &str) -> Result<()> {
+    let task_id = Uuid::new_v4().to_string();
     conn.execute(
-        "INSERT INTO tasks (name, status) VALUES (?1, ?2)",
-        params![task_name, "pending"],
+        "INSERT INTO tasks (id, name, status) VALUES (?1, ?2, ?3)",
+        params![task_id, task_name, "pending"],
     )?;
 
-    println!("Task '{}' added to project '{}'", task_name, project_name);
+    println!(
+        "Task '{}' ({}) added to project '{}'",
+        task_name, task_id, project_name
+    );
 
     Ok(())
 }
 
-pub fn update_task(project_name: &str, task_id: i32, new_name: &str) -> Result<()> {
-    let db_name = format!("{}.sqlite", project_name);
-    let conn = Connection::open(&db_name)?;
-
+pub fn update_task(conn: &Connection, task_id: &str, new_name: &str) -> Result<()> {
     conn.execute(
         "UPDATE tasks SET name = ?1 WHERE id = ?2",
         params![new_name, task_id],
@@ -30,10 +29,37 @@ pub fn update_task(project_name: &str, task_id: i32, new_name: &str) -> Result<(
     Ok(())
 }
 
-pub fn move_status_forward(project_name: &str, task_id: i32) -> Result<()> {
-    let db_name = format!("{}.sqlite", project_name);
-    let conn = Connection::open(&db_name)?;
+pub fn list_tasks(conn: &Connection, project_name: &str, only_finished: bool) -> Result<()> {
+    let query = if only_finished {
+        "SELECT id, name, status FROM tasks WHERE status = 'completed'"
+    } else {
+        "SELECT id, name, status FROM tasks WHERE status != 'completed'"
+    };
+
+    let mut stmt = conn.prepare(query)?;
+    let mut rows = stmt.query([])?;
+
+    let mut found = false;
+    while let Some(row) = rows.next()? {
+        found = true;
+        let id: String = row.get(0)?;
+        let name: String = row.get(1)?;
+        let status: String = row.get(2)?;
+        println!("[{}] {} - {}", id, name, status);
+    }
+
+    if !found {
+        if only_finished {
+            println!("No finished tasks in project '{}'", project_name);
+        } else {
+            println!("No active tasks in project '{}'", project_name);
+        }
+    }
+
+    Ok(())
+}
 
+pub fn move_status_forward(conn: &Connection, task_id: &str) -> Result<()> {
     let mut stmt = conn.prepare("SELECT status FROM tasks WHERE id = ?1")?;
     let mut rows = stmt.query(params![task_id])?;
 